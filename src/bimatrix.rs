@@ -0,0 +1,253 @@
+// Copyright © 2019 Bart Massey
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Non-zero-sum bimatrix games, solved for one mixed Nash
+//! equilibrium by the Lemke–Howson complementary pivoting
+//! algorithm (Lemke and Howson, *Equilibrium Points of
+//! Bimatrix Games*, 1964).
+//!
+//! Unlike `Schema`, which assumes a single zero-sum payoff
+//! matrix, a [`Bimatrix`] carries separate payoff matrices
+//! for the row and column players, so it can represent games
+//! like the Prisoner's Dilemma or Hawk–Dove where the
+//! players' interests are not simply opposed.
+
+use ndarray::prelude::*;
+
+/// A two-player game given as a payoff matrix for each
+/// player. Both matrices have the same shape: row `i`,
+/// column `j` is the payoff to each player when the row
+/// player plays strategy `i` and the column player plays
+/// strategy `j`.
+#[derive(Debug, Clone)]
+pub struct Bimatrix {
+    /// Row player's payoffs.
+    pub row_payoffs: Array2<f64>,
+    /// Column player's payoffs.
+    pub col_payoffs: Array2<f64>,
+}
+
+/// Values below this magnitude are treated as zero when
+/// pivoting, to absorb the usual floating-point noise of
+/// Gauss-Jordan elimination.
+const EPS: f64 = 1e-9;
+
+/// The largest number of complementary pivots to take before
+/// giving up. The path length is bounded in practice, but a
+/// cap guards against a logic error turning into an infinite
+/// loop.
+const MAX_PIVOTS: usize = 10_000;
+
+impl Bimatrix {
+    /// Take nested-`Vec` payoff matrices for the row and
+    /// column players and make them a `Bimatrix`.
+    /// # Panics
+    /// This code will panic if either matrix is empty, if
+    /// their rows are of differing length, or if the two
+    /// matrices do not have the same shape.
+    pub fn new(row_payoffs: Vec<Vec<f64>>, col_payoffs: Vec<Vec<f64>>) -> Self {
+        let to_array = |rows: Vec<Vec<f64>>| {
+            assert!(!rows.is_empty() && !rows[0].is_empty());
+            let ncols = rows[0].len();
+            for r in &rows[1..] {
+                assert!(r.len() == ncols);
+            }
+            let nrows = rows.len();
+            Array2::from_shape_fn((nrows, ncols), |(i, j)| rows[i][j])
+        };
+        let row_payoffs = to_array(row_payoffs);
+        let col_payoffs = to_array(col_payoffs);
+        assert_eq!(row_payoffs.dim(), col_payoffs.dim());
+        Bimatrix {
+            row_payoffs,
+            col_payoffs,
+        }
+    }
+
+    /// Find one mixed Nash equilibrium of this game using the
+    /// Lemke–Howson algorithm, returning the row player's and
+    /// column player's strategies as probability vectors.
+    ///
+    /// A bimatrix game generally has several Nash equilibria;
+    /// this finds exactly one of them, starting from the row
+    /// player's first strategy. Where a pivot's minimum-ratio
+    /// test ties (a degenerate game), the tie goes to the row
+    /// whose basic variable has the smallest index; this is
+    /// not the lexicographic ratio test, and termination on
+    /// degenerate games is not guaranteed.
+    ///
+    /// # Panics
+    /// Panics if the path has not ended after `MAX_PIVOTS`
+    /// pivots.
+    pub fn nash(&self) -> (Vec<f64>, Vec<f64>) {
+        let (m, n) = self.row_payoffs.dim();
+        let (a, _) = shift_positive(&self.row_payoffs);
+        let (b, _) = shift_positive(&self.col_payoffs);
+
+        // Tableau for the row player's polytope: variables
+        // x_0..x_{m-1} (label i) and slacks u_0..u_{n-1}
+        // (label m+j), with row j: sum_i b[i][j] x_i + u_j = 1.
+        let mut row_tab = Array2::<f64>::zeros((n, m + n + 1));
+        for j in 0..n {
+            for i in 0..m {
+                row_tab[(j, i)] = b[(i, j)];
+            }
+            row_tab[(j, m + j)] = 1.0;
+            row_tab[(j, m + n)] = 1.0;
+        }
+        let mut row_basis: Vec<usize> = (0..n).map(|j| m + j).collect();
+
+        // Tableau for the column player's polytope:
+        // variables y_0..y_{n-1} (label m+j) and slacks
+        // w_0..w_{m-1} (label i), with row i: sum_j a[i][j]
+        // y_j + w_i = 1.
+        let mut col_tab = Array2::<f64>::zeros((m, m + n + 1));
+        for i in 0..m {
+            for j in 0..n {
+                col_tab[(i, j)] = a[(i, j)];
+            }
+            col_tab[(i, n + i)] = 1.0;
+            col_tab[(i, m + n)] = 1.0;
+        }
+        let mut col_basis: Vec<usize> = (0..m).map(|i| n + i).collect();
+
+        // The row tableau's variable indices (x_0..x_{m-1}
+        // then u_0..u_{n-1}) already equal their shared
+        // label, by construction above. The column tableau's
+        // do not, since y_0..y_{n-1} come first there; map a
+        // shared label to its column-tableau variable index.
+        let col_var_for_label = |label: usize| -> usize {
+            if label < m {
+                n + label
+            } else {
+                label - m
+            }
+        };
+
+        // Start by dropping label 0 (row strategy 0), forcing
+        // x_0 into the row tableau's basis.
+        let dropped_label = 0;
+        let mut in_row_tableau = true;
+        let mut entering = 0usize;
+
+        let mut terminated = false;
+        for _ in 0..MAX_PIVOTS {
+            let leaving_label = if in_row_tableau {
+                pivot_in(&mut row_tab, &mut row_basis, entering)
+            } else {
+                let departing = pivot_in(&mut col_tab, &mut col_basis, entering);
+                if departing < n {
+                    m + departing
+                } else {
+                    departing - n
+                }
+            };
+            if leaving_label == dropped_label {
+                terminated = true;
+                break;
+            }
+            // Switch polytopes and bring in the variable that
+            // carries the label just vacated.
+            in_row_tableau = !in_row_tableau;
+            entering = if in_row_tableau {
+                leaving_label
+            } else {
+                col_var_for_label(leaving_label)
+            };
+        }
+        assert!(
+            terminated,
+            "Lemke-Howson path did not terminate within {} pivots",
+            MAX_PIVOTS
+        );
+
+        let mut x = vec![0.0; m];
+        for (i, xi) in x.iter_mut().enumerate() {
+            if let Some(row) = row_basis.iter().position(|&b| b == i) {
+                *xi = row_tab[(row, m + n)];
+            }
+        }
+        let mut y = vec![0.0; n];
+        for (j, yj) in y.iter_mut().enumerate() {
+            if let Some(row) = col_basis.iter().position(|&b| b == j) {
+                *yj = col_tab[(row, m + n)];
+            }
+        }
+        normalize(&mut x);
+        normalize(&mut y);
+        (x, y)
+    }
+}
+
+/// Shift a matrix so that every entry is strictly positive,
+/// returning the shifted matrix and the offset added to
+/// each entry.
+fn shift_positive(m: &Array2<f64>) -> (Array2<f64>, f64) {
+    let min = m.iter().cloned().fold(f64::INFINITY, f64::min);
+    let offset = if min <= 0.0 { 1.0 - min } else { 0.0 };
+    (m.map(|v| v + offset), offset)
+}
+
+/// Normalize a vector of non-negative weights to sum to 1,
+/// in place. Leaves an all-zero vector alone.
+fn normalize(v: &mut [f64]) {
+    let total: f64 = v.iter().sum();
+    if total > EPS {
+        for x in v.iter_mut() {
+            *x /= total;
+        }
+    }
+}
+
+/// Pivot `entering` into the basis via the standard
+/// minimum-ratio test, breaking ties in favour of the
+/// smallest basic-variable index. Returns the variable that
+/// left the basis to make room for `entering`.
+fn pivot_in(tab: &mut Array2<f64>, basis: &mut [usize], entering: usize) -> usize {
+    let nrows = basis.len();
+    let rhs = tab.ncols() - 1;
+    let mut leaving = None;
+    let mut best_ratio = f64::INFINITY;
+    for i in 0..nrows {
+        let a_ie = tab[(i, entering)];
+        if a_ie <= EPS {
+            continue;
+        }
+        let ratio = tab[(i, rhs)] / a_ie;
+        let better = match leaving {
+            None => true,
+            Some(l) => {
+                ratio < best_ratio - EPS
+                    || (ratio < best_ratio + EPS && basis[i] < basis[l])
+            }
+        };
+        if better {
+            best_ratio = ratio;
+            leaving = Some(i);
+        }
+    }
+    let leaving = leaving.expect("no valid leaving variable (unbounded Lemke-Howson path)");
+    let departing = basis[leaving];
+
+    let p = tab[(leaving, entering)];
+    let ncols = tab.ncols();
+    for j in 0..ncols {
+        tab[(leaving, j)] /= p;
+    }
+    for i in 0..nrows {
+        if i == leaving {
+            continue;
+        }
+        let factor = tab[(i, entering)];
+        if factor.abs() <= EPS {
+            continue;
+        }
+        for j in 0..ncols {
+            tab[(i, j)] -= factor * tab[(leaving, j)];
+        }
+    }
+    basis[leaving] = entering;
+    departing
+}
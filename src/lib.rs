@@ -79,17 +79,169 @@
 //! Hero will end up about 1 point ahead.
 
 use std::fmt::{self, Display, Formatter};
-use std::io::{self, Write};
+use std::io::{self, BufRead, BufReader, Read, Write};
 use std::ops::{Index, IndexMut};
 
 pub use ndarray;
 use ndarray::{prelude::*, s};
 use ordered_float::OrderedFloat;
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
 use tabwriter::*;
 
+mod simplex;
+
+pub mod bimatrix;
+
+pub mod tournament;
+
+/// Read a payoff matrix in textual space-separated form.
+pub fn read_matrix<T: Read>(r: T) -> io::Result<Vec<Vec<f64>>> {
+    // This is tedious and awkward and error-prone but I
+    // don't have a better idea. Suggestions welcome.
+    let mut rows = Vec::new();
+    let r = BufReader::new(r);
+    for line in r.lines() {
+        let cols: Vec<f64> = line?
+            .split_whitespace()
+            .map(|f| {
+                f.trim().parse().map_err(|e| {
+                    let ek = io::ErrorKind::InvalidData;
+                    io::Error::new(ek, e)
+                })
+            })
+            .collect::<io::Result<Vec<f64>>>()?;
+        if cols.is_empty() {
+            continue;
+        }
+        rows.push(cols);
+    }
+    assert!(!rows[0].is_empty());
+    if rows.is_empty() {
+        let ek = io::ErrorKind::InvalidData;
+        return Err(io::Error::new(ek, "empty matrix"));
+    }
+    let ncols = rows[0].len();
+    for r in &rows[1..] {
+        if r.len() != ncols {
+            let ek = io::ErrorKind::InvalidData;
+            return Err(io::Error::new(ek, "ragged matrix"));
+        }
+    }
+    Ok(rows)
+}
+
+#[test]
+fn test_read_matrix() {
+    let f = io::Cursor::new(b"  1 2 \n\n3 4");
+    let m = read_matrix(f).unwrap();
+    assert_eq!(m, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+}
+
+/// Read a payoff matrix given as JSON: an array of arrays of
+/// numbers, e.g. `[[1,2],[3,4]]`.
+#[cfg(feature = "json")]
+pub fn read_matrix_json<T: Read>(r: T) -> io::Result<Vec<Vec<f64>>> {
+    serde_json::from_reader(r).map_err(|e| {
+        let ek = io::ErrorKind::InvalidData;
+        io::Error::new(ek, e)
+    })
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_read_matrix_json() {
+    let f = io::Cursor::new(b"[[1, 2], [3, 4]]");
+    let m = read_matrix_json(f).unwrap();
+    assert_eq!(m, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+}
+
+/// Row labels, column labels, and payoffs read by
+/// `read_labeled_matrix()`.
+type LabeledMatrix = (Vec<String>, Vec<String>, Vec<Vec<f64>>);
+
+/// Read a labeled payoff matrix: a header line of column
+/// labels, followed by one line per row giving the row's
+/// label and then its space-separated payoffs, e.g.
+/// ```text
+///      M  S  L
+///   M  0  2 -1
+///   S -1  0  1
+///   L  1 -1  0
+/// ```
+pub fn read_labeled_matrix<T: Read>(r: T) -> io::Result<LabeledMatrix> {
+    let mut lines = BufReader::new(r).lines();
+    let header = loop {
+        match lines.next() {
+            Some(line) => {
+                let line = line?;
+                if !line.trim().is_empty() {
+                    break line;
+                }
+            },
+            None => {
+                let ek = io::ErrorKind::InvalidData;
+                return Err(io::Error::new(ek, "empty labeled matrix"));
+            },
+        }
+    };
+    let col_labels: Vec<String> =
+        header.split_whitespace().map(String::from).collect();
+
+    let mut row_labels = Vec::new();
+    let mut rows = Vec::new();
+    for line in lines {
+        let line = line?;
+        let mut fields = line.split_whitespace();
+        let label = match fields.next() {
+            Some(label) => label.to_string(),
+            None => continue,
+        };
+        let cols: Vec<f64> = fields
+            .map(|f| {
+                f.trim().parse().map_err(|e| {
+                    let ek = io::ErrorKind::InvalidData;
+                    io::Error::new(ek, e)
+                })
+            })
+            .collect::<io::Result<Vec<f64>>>()?;
+        row_labels.push(label);
+        rows.push(cols);
+    }
+    if rows.is_empty() {
+        let ek = io::ErrorKind::InvalidData;
+        return Err(io::Error::new(ek, "empty labeled matrix"));
+    }
+    let ncols = col_labels.len();
+    for r in &rows {
+        if r.len() != ncols {
+            let ek = io::ErrorKind::InvalidData;
+            return Err(io::Error::new(ek, "ragged matrix"));
+        }
+    }
+    Ok((row_labels, col_labels, rows))
+}
+
+#[test]
+fn test_read_labeled_matrix() {
+    let f = io::Cursor::new(b"  M  S  L\nM  0  2 -1\nS -1  0  1\nL  1 -1  0\n");
+    let (row_labels, col_labels, m) = read_labeled_matrix(f).unwrap();
+    assert_eq!(row_labels, vec!["M", "S", "L"]);
+    assert_eq!(col_labels, vec!["M", "S", "L"]);
+    assert_eq!(
+        m,
+        vec![
+            vec![0.0, 2.0, -1.0],
+            vec![-1.0, 0.0, 1.0],
+            vec![1.0, -1.0, 0.0],
+        ]
+    );
+}
+
 /// The name of a row or column. These can appear on the
 /// left or right of the schema.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct Name(pub Option<usize>);
 
 impl Display for Name {
@@ -113,6 +265,7 @@ use Edge::*;
 
 /// `Labels` are names to be associated with an
 #[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct Labels(pub [Vec<Name>; 4]);
 
 impl Index<Edge> for Labels {
@@ -131,6 +284,7 @@ impl IndexMut<Edge> for Labels {
 
 /// Schema describing a two-player hidden information game.
 #[derive(Debug)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct Schema {
     /// Score offset to make the payoffs start all positive during
     /// internal calculations. This does not affect the calculated
@@ -144,6 +298,21 @@ pub struct Schema {
     /// Payoff matrix. Note that the dimensions include the
     /// margins.
     pub payoffs: Array2<f64>,
+    /// Row and column counts of the original payoff matrix
+    /// passed to `from_matrix()`, before any dominated
+    /// strategies were removed by `reduce_dominated()`. Used
+    /// to size `Solution`'s strategy vectors so that a
+    /// reduced schema still reports over the original
+    /// dimensions.
+    pub orig_dim: (usize, usize),
+    /// Human-readable names for the original rows, set by
+    /// `from_labeled_matrix()`. Indexed by original row index,
+    /// so it stays valid even after `reduce_dominated()` has
+    /// shrunk `names`.
+    pub row_labels: Option<Vec<String>>,
+    /// Human-readable names for the original columns, set by
+    /// `from_labeled_matrix()`.
+    pub col_labels: Option<Vec<String>>,
 }
 
 /// Display a `Schema` in tabular format.
@@ -186,37 +355,143 @@ impl Display for Schema {
 /// A game solution, given as the value of the game and
 /// an optimal mixed strategy for each player.
 #[derive(Debug)]
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 pub struct Solution {
     /// Value of game.
     pub value: f64,
-    /// Strategy for left player (maximizer, "Blue").
+    /// Strategy for the column player (minimizer, "Red"),
+    /// indexed by original column despite the field's name.
     pub left_strategy: Vec<f64>,
-    /// Strategy for top player (minimizer, "Red").
+    /// Strategy for the row player (maximizer, "Blue"),
+    /// indexed by original row despite the field's name.
     pub top_strategy: Vec<f64>,
+    /// Human-readable names for `left_strategy`'s entries, if
+    /// the schema was built with `from_labeled_matrix()`. Used
+    /// by `Display` in place of bare indices.
+    pub left_labels: Option<Vec<String>>,
+    /// Human-readable names for `top_strategy`'s entries, if
+    /// the schema was built with `from_labeled_matrix()`.
+    pub top_labels: Option<Vec<String>>,
 }
 
 impl Display for Solution {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         writeln!(f, "value {:.3}", self.value)?;
-        let mut show = |name, vals: &[f64]| {
+        let mut show = |name, vals: &[f64], labels: &Option<Vec<String>>| {
             write!(f, "{}", name)?;
             for (i, v) in vals.iter().enumerate() {
-                write!(f, " {}:{:.3}", i, v)?;
+                match labels {
+                    Some(labels) => write!(f, " {}:{:.3}", labels[i], v)?,
+                    None => write!(f, " {}:{:.3}", i, v)?,
+                }
             }
             writeln!(f)
         };
-        show("max", &self.left_strategy)?;
-        show("min", &self.top_strategy)?;
+        show("max", &self.left_strategy, &self.left_labels)?;
+        show("min", &self.top_strategy, &self.top_labels)?;
         Ok(())
     }
 }
 
+/// Selects which algorithm `Schema::solve_with()` uses to
+/// find an optimal mixed strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolveMethod {
+    /// The *Compleat Strategyst* pivoting recurrence used by
+    /// `Schema::solve()`.
+    Pivot,
+    /// The two-phase simplex LP solver used by
+    /// `Schema::solve_lp()`.
+    Lp,
+}
+
+/// A single dominated strategy found by `reduce_dominated()`,
+/// identified by its index in the live (not original) schema.
+enum Removal {
+    Row(usize),
+    Col(usize),
+}
+
+/// Find a row of `ps` that is entrywise no better, for the
+/// maximizer, than some other row.
+fn find_dominated_row(ps: &ArrayView2<f64>) -> Option<usize> {
+    let nr = ps.nrows();
+    (0..nr).find(|&i| {
+        (0..nr).any(|k| {
+            k != i
+                && ps.row(i)
+                    .iter()
+                    .zip(ps.row(k).iter())
+                    .all(|(a, b)| a <= b)
+        })
+    })
+}
+
+/// Find a column of `ps` that is entrywise no better, for the
+/// minimizer, than some other column.
+fn find_dominated_col(ps: &ArrayView2<f64>) -> Option<usize> {
+    let nc = ps.ncols();
+    (0..nc).find(|&j| {
+        (0..nc).any(|k| {
+            k != j
+                && ps.column(j)
+                    .iter()
+                    .zip(ps.column(k).iter())
+                    .all(|(a, b)| a >= b)
+        })
+    })
+}
+
+/// Re-augment a bare payoff matrix with the usual margins: a
+/// right column of 1.0, a bottom row of -1.0, and a 0.0 in
+/// the corner, matching `Schema::from_matrix()`.
+fn rebuild_payoffs(ps: Array2<f64>) -> Array2<f64> {
+    let (nr, nc) = ps.dim();
+    let mut payoffs = Array2::zeros((nr + 1, nc + 1));
+    payoffs.slice_mut(s![..nr, ..nc]).assign(&ps);
+    for c in 0..nc {
+        payoffs[(nr, c)] = -1.0;
+    }
+    for r in 0..nr {
+        payoffs[(r, nc)] = 1.0;
+    }
+    payoffs
+}
+
 impl Schema {
     /// Take a nested-`Vec` payoff matrix and make it a `Schema`.
     /// # Panics
     /// This code will panic if the input matrix is empty
     /// or the rows are of differing length.
-    pub fn from_matrix(mut rows: Vec<Vec<f64>>) -> Self {
+    pub fn from_matrix(rows: Vec<Vec<f64>>) -> Self {
+        Schema::build(rows, None, None)
+    }
+
+    /// Take a nested-`Vec` payoff matrix together with names
+    /// for its rows and columns, and make it a `Schema`. The
+    /// names are carried through to `Solution`'s `Display` in
+    /// place of bare indices.
+    /// # Panics
+    /// This code will panic if the input matrix is empty, the
+    /// rows are of differing length, or either label `Vec`
+    /// does not match the matrix's corresponding dimension.
+    pub fn from_labeled_matrix(
+        rows: Vec<Vec<f64>>,
+        row_labels: Vec<String>,
+        col_labels: Vec<String>,
+    ) -> Self {
+        assert_eq!(rows.len(), row_labels.len());
+        assert_eq!(rows[0].len(), col_labels.len());
+        Schema::build(rows, Some(row_labels), Some(col_labels))
+    }
+
+    /// Shared construction logic for `from_matrix()` and
+    /// `from_labeled_matrix()`.
+    fn build(
+        mut rows: Vec<Vec<f64>>,
+        row_labels: Option<Vec<String>>,
+        col_labels: Option<Vec<String>>,
+    ) -> Self {
         assert!(!rows.is_empty() && !rows[0].is_empty());
         let ncols = rows[0].len();
         for r in &rows[1..] {
@@ -252,6 +527,9 @@ impl Schema {
             d: 1.0,
             names,
             payoffs,
+            orig_dim: (nrows - 1, ncols - 1),
+            row_labels,
+            col_labels,
         }
     }
 
@@ -333,9 +611,10 @@ impl Schema {
         // Step 6
         let nr = self.names[Left].len();
         let nc = self.names[Top].len();
+        let (onr, onc) = self.orig_dim;
 
         let mut tr = 0.0;
-        let mut left_strategy = vec![0.0; nr];
+        let mut left_strategy = vec![0.0; onc];
         for (r, &n) in self.names[Right].iter().enumerate() {
             if let Name(Some(sr)) = n {
                 let p = self.payoffs[(r, nc)];
@@ -349,7 +628,7 @@ impl Schema {
         }
 
         let mut tc = 0.0;
-        let mut top_strategy = vec![0.0; nc];
+        let mut top_strategy = vec![0.0; onr];
         for (c, &n) in self.names[Bottom].iter().enumerate() {
             if let Name(Some(sc)) = n {
                 let p = self.payoffs[(nr, c)];
@@ -369,22 +648,264 @@ impl Schema {
         Solution {
             left_strategy,
             top_strategy,
+            left_labels: self.col_labels.clone(),
+            top_labels: self.row_labels.clone(),
             value,
         }
     }
 
+    /// Repeatedly remove rows and columns that can never be
+    /// part of an optimal strategy: a row that is
+    /// entrywise no better than some other row for the
+    /// maximizer, or a column that is entrywise no better
+    /// than some other column for the minimizer. Stops as
+    /// soon as only one row or only one column remains, or
+    /// no more dominated strategies can be found.
+    ///
+    /// Removed strategies are simply dropped from the live
+    /// schema; `orig_dim` remembers the original dimensions
+    /// so that `solution()` still reports a probability (of
+    /// 0) for each of them.
+    pub fn reduce_dominated(&mut self) {
+        loop {
+            let removal = {
+                let ps = self.payoffs.slice(s![..-1, ..-1]);
+                let (nr, nc) = ps.dim();
+                if nr <= 1 || nc <= 1 {
+                    None
+                } else if let Some(i) = find_dominated_row(&ps) {
+                    Some(Removal::Row(i))
+                } else {
+                    find_dominated_col(&ps).map(Removal::Col)
+                }
+            };
+            match removal {
+                Some(Removal::Row(i)) => self.remove_row(i),
+                Some(Removal::Col(j)) => self.remove_col(j),
+                None => break,
+            }
+        }
+    }
+
+    /// Remove row `i` (of the live, possibly already-reduced
+    /// schema) along with its margin entry, and rebuild the
+    /// margins of what remains.
+    fn remove_row(&mut self, i: usize) {
+        let ps = self.payoffs.slice(s![..-1, ..-1]);
+        let (nr, nc) = ps.dim();
+        let inner = Array2::from_shape_fn((nr - 1, nc), |(r, c)| {
+            ps[(if r < i { r } else { r + 1 }, c)]
+        });
+        self.payoffs = rebuild_payoffs(inner);
+        self.names[Left].remove(i);
+        self.names[Right].remove(i);
+    }
+
+    /// Remove column `j` (of the live, possibly
+    /// already-reduced schema) along with its margin entry,
+    /// and rebuild the margins of what remains.
+    fn remove_col(&mut self, j: usize) {
+        let ps = self.payoffs.slice(s![..-1, ..-1]);
+        let (nr, nc) = ps.dim();
+        let inner = Array2::from_shape_fn((nr, nc - 1), |(r, c)| {
+            ps[(r, if c < j { c } else { c + 1 })]
+        });
+        self.payoffs = rebuild_payoffs(inner);
+        self.names[Top].remove(j);
+        self.names[Bottom].remove(j);
+    }
+
+    /// Check whether the live schema already has a
+    /// pure-strategy solution: an entry that is
+    /// simultaneously the smallest in its row and the
+    /// largest in its column, so that neither player can
+    /// improve by deviating alone.
+    ///
+    /// Returns the original row and column index of that
+    /// entry (even if dominance elimination has since
+    /// renumbered the live schema) together with the value
+    /// of the game, or `None` if there is no saddle point.
+    pub fn saddle_point(&self) -> Option<(usize, usize, f64)> {
+        let ps = self.payoffs.slice(s![..-1, ..-1]);
+        let (nr, nc) = ps.dim();
+        for r in 0..nr {
+            let row_min = ps.row(r).iter().cloned().fold(f64::INFINITY, f64::min);
+            for c in 0..nc {
+                let v = ps[(r, c)];
+                if v != row_min {
+                    continue;
+                }
+                let col_max = ps.column(c).iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+                if v == col_max {
+                    let orig_r = match self.names[Left][r] {
+                        Name(Some(i)) => i,
+                        Name(None) => unreachable!("live row must carry an original index"),
+                    };
+                    let orig_c = match self.names[Top][c] {
+                        Name(Some(i)) => i,
+                        Name(None) => unreachable!("live column must carry an original index"),
+                    };
+                    return Some((orig_r, orig_c, v + self.offset));
+                }
+            }
+        }
+        None
+    }
+
     /// Find optimal strategies and game value for the given
-    /// schema. This is a convenience function that proceeds
-    /// by calling `find_pivot()` and `reduce()` iteratively
-    /// until the schema is fully reduced, then calling
-    /// `solution()` to get the solution.
+    /// schema. This first calls `reduce_dominated()` to
+    /// shrink the problem, then checks `saddle_point()` for a
+    /// pure-strategy solution; failing that, it proceeds by
+    /// calling `find_pivot()` and `reduce()` iteratively until
+    /// the schema is fully reduced, then calling `solution()`
+    /// to get the solution.
     pub fn solve(&mut self) -> Solution {
         // *Compleat Strategyst* p. 226
         // Step 6
+        self.reduce_dominated();
+
+        if let Some((r, c, value)) = self.saddle_point() {
+            let (onr, onc) = self.orig_dim;
+            let mut left_strategy = vec![0.0; onc];
+            left_strategy[c] = 1.0;
+            let mut top_strategy = vec![0.0; onr];
+            top_strategy[r] = 1.0;
+            return Solution {
+                value,
+                left_strategy,
+                top_strategy,
+                left_labels: self.col_labels.clone(),
+                top_labels: self.row_labels.clone(),
+            };
+        }
+
         while let Some(p) = self.find_pivot() {
             self.reduce(p);
         }
 
         self.solution()
     }
+
+    /// Find optimal strategies and game value by solving the
+    /// equivalent linear program with a dense two-phase
+    /// simplex (Bland's rule throughout, to avoid cycling)
+    /// instead of pivoting. Unlike `solve()`, this does not
+    /// mutate the schema, and it keeps working on degenerate
+    /// matrices where `reduce()` can stall or divide by a
+    /// zero `d`. Useful as a cross-check against `solve()`.
+    pub fn solve_lp(&self) -> Solution {
+        let ps = self.payoffs.slice(s![..-1, ..-1]);
+        let (value, row_strategy, col_strategy) = simplex::solve_game(&ps);
+        // `solution()` above hands back the row player's mix
+        // as `top_strategy` and the column player's as
+        // `left_strategy` (the pivoting recurrence's Left/Top
+        // margins track the *other* player's basic
+        // variables); match that so the two solvers agree.
+        //
+        // `row_strategy`/`col_strategy` are indexed over the
+        // *live* schema, which may be smaller than `orig_dim`
+        // if `reduce_dominated()` has run; scatter them back
+        // out to original-index-sized vectors, as `solution()`
+        // does, so a reduced schema still reports over the
+        // original dimensions.
+        let (onr, onc) = self.orig_dim;
+
+        let mut top_strategy = vec![0.0; onr];
+        for (r, &p) in row_strategy.iter().enumerate() {
+            if let Name(Some(orig_r)) = self.names[Left][r] {
+                top_strategy[orig_r] = p;
+            }
+        }
+        let mut left_strategy = vec![0.0; onc];
+        for (c, &p) in col_strategy.iter().enumerate() {
+            if let Name(Some(orig_c)) = self.names[Top][c] {
+                left_strategy[orig_c] = p;
+            }
+        }
+
+        Solution {
+            value: value + self.offset,
+            left_strategy,
+            top_strategy,
+            left_labels: self.col_labels.clone(),
+            top_labels: self.row_labels.clone(),
+        }
+    }
+
+    /// Find optimal strategies and game value using whichever
+    /// of `solve()` or `solve_lp()` is selected by `method`.
+    pub fn solve_with(&mut self, method: SolveMethod) -> Solution {
+        match method {
+            SolveMethod::Pivot => self.solve(),
+            SolveMethod::Lp => self.solve_lp(),
+        }
+    }
+
+    /// Solve a 2×2 game directly by the standard indifference
+    /// formulas, instead of pivoting: given payoffs `[[a,b],
+    /// [c,d]]`, the row player's mix `p` on row 0 and the
+    /// column player's mix `q` on column 0 are
+    /// `p = (d-c)/((a-c)+(d-b))`, `q = (d-b)/((a-c)+(d-b))`,
+    /// with value `v = (a*d-b*c)/((a-c)+(d-b))`. This is exact
+    /// up to floating-point roundoff, unlike the accumulated
+    /// error of iterative pivoting, and is useful as a fast
+    /// path and as a test oracle for `solve()`.
+    ///
+    /// Falls back to the pure-strategy `saddle_point()` when
+    /// the indifference formulas' denominator is zero or the
+    /// resulting mix falls outside `[0, 1]`.
+    /// # Panics
+    /// Panics if the schema is not 2×2, or if dominance
+    /// elimination has already shrunk it. A schema that
+    /// `reduce()` has already pivoted keeps its 2×2 shape and
+    /// is not detected.
+    pub fn solve_2x2_exact(&self) -> Solution {
+        assert_eq!(self.orig_dim, (2, 2));
+        assert_eq!(self.names[Left].len(), 2);
+        assert_eq!(self.names[Top].len(), 2);
+
+        let a = self.payoffs[(0, 0)] + self.offset;
+        let b = self.payoffs[(0, 1)] + self.offset;
+        let c = self.payoffs[(1, 0)] + self.offset;
+        let d = self.payoffs[(1, 1)] + self.offset;
+
+        let denom = (a - c) + (d - b);
+        let mixed = if denom != 0.0 {
+            let p = (d - c) / denom;
+            let q = (d - b) / denom;
+            if (0.0..=1.0).contains(&p) && (0.0..=1.0).contains(&q) {
+                Some((p, q))
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        if let Some((p, q)) = mixed {
+            let value = (a * d - b * c) / denom;
+            return Solution {
+                value,
+                left_strategy: vec![q, 1.0 - q],
+                top_strategy: vec![p, 1.0 - p],
+                left_labels: self.col_labels.clone(),
+                top_labels: self.row_labels.clone(),
+            };
+        }
+
+        let (r, c_idx, value) = self
+            .saddle_point()
+            .expect("2x2 game without a valid mix must have a saddle point");
+        let mut left_strategy = vec![0.0; 2];
+        left_strategy[c_idx] = 1.0;
+        let mut top_strategy = vec![0.0; 2];
+        top_strategy[r] = 1.0;
+        Solution {
+            value,
+            left_strategy,
+            top_strategy,
+            left_labels: self.col_labels.clone(),
+            top_labels: self.row_labels.clone(),
+        }
+    }
 }
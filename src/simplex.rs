@@ -0,0 +1,217 @@
+// Copyright © 2019 Bart Massey
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! A small dense two-phase simplex solver used by
+//! `Schema::solve_lp()` as an alternative to the pivoting
+//! recurrence in `reduce()`. This is a private
+//! implementation detail of the crate, not a general-purpose
+//! LP library: it knows only how to solve the particular LP
+//! that falls out of a zero-sum game's payoff matrix.
+
+use ndarray::prelude::*;
+
+/// Values below this magnitude are treated as zero when
+/// choosing pivots or testing for optimality, to absorb the
+/// usual floating-point noise of Gauss-Jordan elimination.
+const EPS: f64 = 1e-9;
+
+/// Solve the zero-sum game given by the non-negative payoff
+/// matrix `a` (row player maximizes, column player
+/// minimizes) for its value and optimal mixed strategies,
+/// using a two-phase simplex instead of pivoting.
+///
+/// `a` must have every entry non-negative (the caller is
+/// expected to have already applied the usual offset), and
+/// must be non-empty. Returns the game's value together with
+/// the row player's and column player's strategies, all
+/// still relative to the un-offset value of `a`: the caller
+/// is responsible for adding back any offset.
+pub(crate) fn solve_game(a: &ArrayView2<f64>) -> (f64, Vec<f64>, Vec<f64>) {
+    let (r, c) = a.dim();
+    assert!(r > 0 && c > 0);
+
+    // Variables, in tableau column order: r row-mixture
+    // weights `x`, the game value `v`, c slacks `s` (one
+    // per column constraint), and one artificial variable
+    // `a_eq` for the `sum x = 1` equality.
+    let v_idx = r;
+    let slack = |j: usize| r + 1 + j;
+    let a_eq_idx = r + 1 + c;
+    let nvars = r + 1 + c + 1;
+
+    // Row j (0..c): -sum_i a[i][j] x_i + v + s_j = 0
+    // Row c        : sum_i x_i + a_eq = 1
+    let mut tab = Array2::zeros((c + 1, nvars + 1));
+    for j in 0..c {
+        for i in 0..r {
+            tab[(j, i)] = -a[(i, j)];
+        }
+        tab[(j, v_idx)] = 1.0;
+        tab[(j, slack(j))] = 1.0;
+    }
+    for i in 0..r {
+        tab[(c, i)] = 1.0;
+    }
+    tab[(c, a_eq_idx)] = 1.0;
+    tab[(c, nvars)] = 1.0;
+
+    let mut basis: Vec<usize> = (0..c).map(slack).collect();
+    basis.push(a_eq_idx);
+
+    // Phase I: drive the artificial variable out of the
+    // basis by minimizing it, i.e. maximizing -a_eq.
+    let mut cost = vec![0.0; nvars];
+    cost[a_eq_idx] = -1.0;
+    simplex_iterate(&mut tab, &mut basis, &cost, None);
+
+    // The game is always feasible (e.g. play row 0 with
+    // probability 1), so phase I should leave a_eq at 0. If
+    // it is still basic at a degenerate zero level, pivot it
+    // out so phase II never considers it again.
+    if let Some(row) = basis.iter().position(|&b| b == a_eq_idx) {
+        let out = (0..nvars)
+            .find(|&j| j != a_eq_idx && tab[(row, j)].abs() > EPS);
+        if let Some(j) = out {
+            pivot(&mut tab, j, row);
+            basis[row] = j;
+        }
+    }
+
+    // Phase II: maximize the game value v, ignoring the
+    // (now zero and useless) artificial column.
+    let mut cost = vec![0.0; nvars];
+    cost[v_idx] = 1.0;
+    simplex_iterate(&mut tab, &mut basis, &cost, Some(a_eq_idx));
+
+    let value = basis
+        .iter()
+        .position(|&b| b == v_idx)
+        .map(|row| tab[(row, nvars)])
+        .unwrap_or(0.0);
+
+    let mut left_strategy = vec![0.0; r];
+    for (i, ls) in left_strategy.iter_mut().enumerate() {
+        if let Some(row) = basis.iter().position(|&b| b == i) {
+            *ls = tab[(row, nvars)];
+        }
+    }
+    normalize(&mut left_strategy);
+
+    // The column player's strategy is recovered from the
+    // dual values of the c column constraints, which at
+    // optimality are the negated reduced costs of their
+    // slack variables.
+    let obj = objective_row(&tab, &basis, &cost);
+    let mut top_strategy = vec![0.0; c];
+    for (j, ts) in top_strategy.iter_mut().enumerate() {
+        *ts = -obj[slack(j)];
+    }
+    normalize(&mut top_strategy);
+
+    (value, left_strategy, top_strategy)
+}
+
+/// Normalize a vector of non-negative weights to sum to 1,
+/// in place. Leaves an all-zero vector alone.
+fn normalize(v: &mut [f64]) {
+    let total: f64 = v.iter().sum();
+    if total > EPS {
+        for x in v.iter_mut() {
+            *x /= total;
+        }
+    }
+}
+
+/// Compute the reduced-cost row `c_j - z_j` for every
+/// tableau column (including the RHS column, where it gives
+/// the negated current objective value) given the current
+/// basis and a cost vector indexed by variable.
+fn objective_row(tab: &Array2<f64>, basis: &[usize], cost: &[f64]) -> Array1<f64> {
+    let ncols = tab.ncols();
+    Array1::from_shape_fn(ncols, |j| {
+        let zj: f64 = basis
+            .iter()
+            .enumerate()
+            .map(|(i, &bi)| cost[bi] * tab[(i, j)])
+            .sum();
+        let cj = if j < cost.len() { cost[j] } else { 0.0 };
+        cj - zj
+    })
+}
+
+/// Run the simplex method to maximize `cost` over the
+/// feasible region described by `tab` and `basis`, using
+/// Bland's rule throughout (smallest-index entering and
+/// leaving variable) to guarantee termination on the
+/// degenerate matrices that make the pivoting recurrence
+/// misbehave. `skip` names a variable (if any) that must
+/// never be chosen to enter the basis.
+fn simplex_iterate(
+    tab: &mut Array2<f64>,
+    basis: &mut [usize],
+    cost: &[f64],
+    skip: Option<usize>,
+) {
+    let nvars = tab.ncols() - 1;
+    loop {
+        let obj = objective_row(tab, basis, cost);
+        let entering = (0..nvars)
+            .filter(|&j| Some(j) != skip)
+            .find(|&j| obj[j] > EPS);
+        let entering = match entering {
+            Some(j) => j,
+            None => return,
+        };
+
+        let mut leaving = None;
+        let mut best_ratio = f64::INFINITY;
+        for (i, &bi) in basis.iter().enumerate() {
+            let a_ie = tab[(i, entering)];
+            if a_ie <= EPS {
+                continue;
+            }
+            let ratio = tab[(i, nvars)] / a_ie;
+            let better = match leaving {
+                None => true,
+                Some(l) => {
+                    ratio < best_ratio - EPS
+                        || (ratio < best_ratio + EPS && bi < basis[l])
+                }
+            };
+            if better {
+                best_ratio = ratio;
+                leaving = Some(i);
+            }
+        }
+        // The game LPs this module solves are always bounded
+        // (x and the duals live in a simplex), so an unbounded
+        // column here means a logic error upstream.
+        let leaving = leaving.expect("unbounded LP");
+        pivot(tab, entering, leaving);
+        basis[leaving] = entering;
+    }
+}
+
+/// Gauss-Jordan eliminate column `entering` using row
+/// `leaving` as the pivot row.
+fn pivot(tab: &mut Array2<f64>, entering: usize, leaving: usize) {
+    let (nrows, ncols) = tab.dim();
+    let p = tab[(leaving, entering)];
+    for j in 0..ncols {
+        tab[(leaving, j)] /= p;
+    }
+    for i in 0..nrows {
+        if i == leaving {
+            continue;
+        }
+        let factor = tab[(i, entering)];
+        if factor.abs() <= EPS {
+            continue;
+        }
+        for j in 0..ncols {
+            tab[(i, j)] -= factor * tab[(leaving, j)];
+        }
+    }
+}
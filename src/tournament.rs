@@ -0,0 +1,184 @@
+// Copyright © 2019 Bart Massey
+// [This program is licensed under the "MIT License"]
+// Please see the file LICENSE in the source
+// distribution of this software for license terms.
+
+//! Repeated-game simulation: play a solved `Schema`'s
+//! optimal mixed strategy against fixed or adaptive
+//! opponents over many rounds, and tabulate average scores
+//! across a round-robin of several such `Strategy`s. This is
+//! a way to empirically check that a computed `Solution`
+//! really does secure the game's value, and to see how it
+//! fares against non-equilibrium play.
+
+use ndarray::{Array2, ArrayView2};
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::Solution;
+
+/// A player in a repeated game. `act()` is given the
+/// history of the match so far, from this player's own
+/// point of view: `history[k] = (my_move, opponent_move)`
+/// for round `k`. It returns this player's move (a row or
+/// column index into the game's payoff matrix) for the next
+/// round.
+pub trait Strategy {
+    fn act(&mut self, history: &[(usize, usize)]) -> usize;
+}
+
+/// Plays a strategy's mixed probabilities directly, sampling
+/// a fresh move each round from a seeded RNG. This is the
+/// strategy a solved `Schema` recommends.
+pub struct OptimalStrategy {
+    dist: WeightedIndex<f64>,
+    rng: StdRng,
+}
+
+impl OptimalStrategy {
+    /// Sample moves according to `probs`, using `seed` to
+    /// make play reproducible.
+    /// # Panics
+    /// Panics if `probs` is empty or all zero.
+    pub fn new(probs: &[f64], seed: u64) -> Self {
+        let dist = WeightedIndex::new(probs).expect("probs must have a positive weight");
+        OptimalStrategy {
+            dist,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Play the row player's (maximizer's) strategy. Despite
+    /// its name, `soln.top_strategy` is the one indexed by
+    /// row (see the comment in `Schema::solve_lp` for why); use
+    /// this to drive `run_match`'s `strat_a`, whose moves are
+    /// row indices into the payoff matrix.
+    pub fn from_row(soln: &Solution, seed: u64) -> Self {
+        OptimalStrategy::new(&soln.top_strategy, seed)
+    }
+
+    /// Play the column player's (minimizer's) strategy.
+    /// Despite its name, `soln.left_strategy` is the one
+    /// indexed by column; use this to drive `run_match`'s
+    /// `strat_b`, whose moves are column indices into the
+    /// payoff matrix.
+    pub fn from_col(soln: &Solution, seed: u64) -> Self {
+        OptimalStrategy::new(&soln.left_strategy, seed)
+    }
+}
+
+impl Strategy for OptimalStrategy {
+    fn act(&mut self, _history: &[(usize, usize)]) -> usize {
+        self.dist.sample(&mut self.rng)
+    }
+}
+
+/// Plays `first_move` in the first round, then copies the
+/// opponent's previous move thereafter.
+pub struct TitForTat {
+    first_move: usize,
+}
+
+impl TitForTat {
+    pub fn new(first_move: usize) -> Self {
+        TitForTat { first_move }
+    }
+}
+
+impl Strategy for TitForTat {
+    fn act(&mut self, history: &[(usize, usize)]) -> usize {
+        match history.last() {
+            Some(&(_, opponent_move)) => opponent_move,
+            None => self.first_move,
+        }
+    }
+}
+
+/// Always plays the same move.
+pub struct AlwaysPick(pub usize);
+
+impl Strategy for AlwaysPick {
+    fn act(&mut self, _history: &[(usize, usize)]) -> usize {
+        self.0
+    }
+}
+
+/// Plays a uniformly random move among `n` choices each
+/// round, using a seeded RNG.
+pub struct UniformRandom {
+    n: usize,
+    rng: StdRng,
+}
+
+impl UniformRandom {
+    pub fn new(n: usize, seed: u64) -> Self {
+        UniformRandom {
+            n,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Strategy for UniformRandom {
+    fn act(&mut self, _history: &[(usize, usize)]) -> usize {
+        self.rng.gen_range(0..self.n)
+    }
+}
+
+/// Play one match of `rounds` rounds between `strat_a` and
+/// `strat_b` against the zero-sum `payoffs` matrix (row
+/// player's payoff at `payoffs[(a_move, b_move)]`, column
+/// player's the negation of that), returning the
+/// accumulated score for each player.
+pub fn run_match(
+    payoffs: &ArrayView2<f64>,
+    strat_a: &mut dyn Strategy,
+    strat_b: &mut dyn Strategy,
+    rounds: usize,
+) -> (f64, f64) {
+    let mut history_a = Vec::with_capacity(rounds);
+    let mut history_b = Vec::with_capacity(rounds);
+    let mut score_a = 0.0;
+    let mut score_b = 0.0;
+    for _ in 0..rounds {
+        let a_move = strat_a.act(&history_a);
+        let b_move = strat_b.act(&history_b);
+        let payoff = payoffs[(a_move, b_move)];
+        score_a += payoff;
+        score_b -= payoff;
+        history_a.push((a_move, b_move));
+        history_b.push((b_move, a_move));
+    }
+    (score_a, score_b)
+}
+
+/// A zero-argument factory producing a fresh `Strategy`
+/// instance for one match; `run_tournament()` takes one of
+/// these per competitor so that every match (including a
+/// competitor against itself) starts from a clean state.
+pub type StrategyFactory = Box<dyn Fn() -> Box<dyn Strategy>>;
+
+/// Play every pair of `factories` against each other over
+/// `repetitions` matches of `rounds` rounds each, against
+/// `payoffs`, and return the table of average scores: entry
+/// `(i, j)` is competitor `i`'s average score as the row
+/// player against competitor `j` as the column player.
+pub fn run_tournament(
+    factories: &[StrategyFactory],
+    payoffs: &ArrayView2<f64>,
+    rounds: usize,
+    repetitions: usize,
+) -> Array2<f64> {
+    let n = factories.len();
+    Array2::from_shape_fn((n, n), |(i, j)| {
+        let total: f64 = (0..repetitions)
+            .map(|_| {
+                let mut a = factories[i]();
+                let mut b = factories[j]();
+                run_match(payoffs, a.as_mut(), b.as_mut(), rounds).0
+            })
+            .sum();
+        total / repetitions as f64
+    })
+}
@@ -2,6 +2,8 @@ use std::io;
 
 use ndarray::prelude::*;
 
+use mixed_strategies::bimatrix::Bimatrix;
+use mixed_strategies::tournament::*;
 use mixed_strategies::*;
 
 #[test]
@@ -140,3 +142,276 @@ fn test_solve() {
         assert!(eqish(s, e));
     }
 }
+
+fn eqish(v1: f64, v2: f64) -> bool {
+    (v1 - v2).abs() < 0.00001
+}
+
+#[test]
+fn test_solve_lp_value_matches_solve() {
+    // eg_schema() is a degenerate matrix (it has tied
+    // optimal strategies), so the LP solver may land on a
+    // different optimal vertex than the pivot recurrence;
+    // only the game value is guaranteed to agree.
+    let mut s = eg_schema();
+    let pivot_soln = s.solve();
+    let lp_soln = eg_schema().solve_lp();
+    assert!(eqish(pivot_soln.value, lp_soln.value));
+    assert!(eqish(lp_soln.value, 14.0/3.0));
+}
+
+#[test]
+fn test_solve_lp_agrees_with_solve() {
+    // *Compleat Strategyst* p. 220, but non-degenerate
+    let m = vec![
+        vec![3.0, 1.0, 0.0, 2.0],
+        vec![1.0, 2.0, 3.0, 0.0],
+        vec![0.0, 3.0, 1.0, 1.0],
+        vec![2.0, 0.0, 2.0, 3.0],
+    ];
+    let mut pivot_schema = Schema::from_matrix(m.clone());
+    let pivot_soln = pivot_schema.solve();
+
+    let lp_schema = Schema::from_matrix(m);
+    let lp_soln = lp_schema.solve_lp();
+
+    assert!(eqish(pivot_soln.value, lp_soln.value));
+    for (&p, &l) in pivot_soln.left_strategy.iter().zip(lp_soln.left_strategy.iter()) {
+        assert!(eqish(p, l));
+    }
+    for (&p, &l) in pivot_soln.top_strategy.iter().zip(lp_soln.top_strategy.iter()) {
+        assert!(eqish(p, l));
+    }
+}
+
+#[test]
+fn test_solve_lp_honors_orig_dim_after_reduce_dominated() {
+    // Row 1 is dominated by row 0, and columns 0 and 1 are
+    // each dominated by column 2; reduce_dominated() here
+    // shrinks the live schema to 2x1, but solve_lp() must
+    // still report strategy vectors sized over the original
+    // 3x3 dimensions, like solve() does.
+    let m = vec![
+        vec![4.0, 5.0, 2.0],
+        vec![1.0, 2.0, 0.0],
+        vec![3.0, 6.0, 1.0],
+    ];
+    let mut s = Schema::from_matrix(m);
+    s.reduce_dominated();
+    let soln = s.solve_lp();
+    assert_eq!(soln.left_strategy.len(), 3);
+    assert_eq!(soln.top_strategy.len(), 3);
+    assert!(eqish(soln.top_strategy[1], 0.0));
+    assert!(eqish(soln.left_strategy[0], 0.0));
+    assert!(eqish(soln.left_strategy[1], 0.0));
+}
+
+#[test]
+fn test_nash_prisoners_dilemma() {
+    // (Cooperate, Defect) for both players; payoffs are
+    // (3,3) (0,5) / (5,0) (1,1). The only Nash equilibrium is
+    // mutual defection.
+    let g = Bimatrix::new(
+        vec![vec![3.0, 0.0], vec![5.0, 1.0]],
+        vec![vec![3.0, 5.0], vec![0.0, 1.0]],
+    );
+    let (row, col) = g.nash();
+    assert!(eqish(row[0], 0.0));
+    assert!(eqish(row[1], 1.0));
+    assert!(eqish(col[0], 0.0));
+    assert!(eqish(col[1], 1.0));
+}
+
+#[test]
+fn test_nash_matching_pennies() {
+    let g = Bimatrix::new(
+        vec![vec![1.0, -1.0], vec![-1.0, 1.0]],
+        vec![vec![-1.0, 1.0], vec![1.0, -1.0]],
+    );
+    let (row, col) = g.nash();
+    for p in row.iter().chain(col.iter()) {
+        assert!(eqish(*p, 0.5));
+    }
+}
+
+#[test]
+fn test_from_labeled_matrix_display() {
+    // The DungeonQuest combat example from the module docs.
+    let payoffs = vec![
+        vec![0.0, 2.0, -1.0],
+        vec![-1.0, 0.0, 1.0],
+        vec![1.0, -1.0, 0.0],
+    ];
+    let names: Vec<String> = ["M", "S", "L"].iter().map(|s| s.to_string()).collect();
+    let mut s = Schema::from_labeled_matrix(payoffs, names.clone(), names);
+    let soln = s.solve();
+    assert!(eqish(soln.value, 1.0 / 12.0));
+    let text = soln.to_string();
+    assert!(text.contains("M:0.333"));
+    assert!(text.contains("S:0.250"));
+    assert!(text.contains("L:0.417"));
+}
+
+#[test]
+fn test_saddle_point() {
+    let s = Schema::from_matrix(vec![vec![10.0, 3.0], vec![4.0, 1.0]]);
+    assert_eq!(s.saddle_point(), Some((0, 1, 3.0)));
+}
+
+#[test]
+fn test_saddle_point_none() {
+    // eg_schema() has no pure-strategy solution.
+    let s = eg_schema();
+    assert_eq!(s.saddle_point(), None);
+}
+
+#[test]
+fn test_solve_short_circuits_on_saddle_point() {
+    let mut s = Schema::from_matrix(vec![vec![10.0, 3.0], vec![4.0, 1.0]]);
+    let soln = s.solve();
+    assert!(eqish(soln.value, 3.0));
+    assert!(eqish(soln.left_strategy[0], 0.0));
+    assert!(eqish(soln.left_strategy[1], 1.0));
+    assert!(eqish(soln.top_strategy[0], 1.0));
+    assert!(eqish(soln.top_strategy[1], 0.0));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_solution_json_round_trip() {
+    let mut s = eg_schema();
+    let soln = s.solve();
+    let json = serde_json::to_string(&soln).unwrap();
+    let back: Solution = serde_json::from_str(&json).unwrap();
+    assert!(eqish(soln.value, back.value));
+    assert_eq!(soln.left_strategy, back.left_strategy);
+    assert_eq!(soln.top_strategy, back.top_strategy);
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_schema_json_round_trip() {
+    let s = eg_schema();
+    let json = serde_json::to_string(&s).unwrap();
+    let back: Schema = serde_json::from_str(&json).unwrap();
+    assert_eq!(s.offset, back.offset);
+    assert_eq!(s.d, back.d);
+    assert_eq!(s.names, back.names);
+    assert_eq!(s.payoffs, back.payoffs);
+}
+
+#[test]
+fn test_reduce_dominated_keeps_orig_dim() {
+    // Row 1 is dominated by row 0, and column 1 is dominated
+    // by column 0 once row 1 is gone.
+    let m = vec![
+        vec![4.0, 5.0, 2.0],
+        vec![1.0, 2.0, 0.0],
+        vec![3.0, 6.0, 1.0],
+    ];
+    let mut s = Schema::from_matrix(m.clone());
+    s.reduce_dominated();
+    assert_eq!(s.orig_dim, (3, 3));
+
+    let mut s = Schema::from_matrix(m);
+    let soln = s.solve();
+    assert_eq!(soln.left_strategy.len(), 3);
+    assert_eq!(soln.top_strategy.len(), 3);
+    assert!(eqish(soln.top_strategy[1], 0.0));
+}
+
+#[test]
+fn test_tit_for_tat() {
+    let mut t = TitForTat::new(0);
+    assert_eq!(t.act(&[]), 0);
+    assert_eq!(t.act(&[(0, 1)]), 1);
+    assert_eq!(t.act(&[(0, 1), (1, 0)]), 0);
+}
+
+#[test]
+fn test_run_match_optimal_secures_value() {
+    // The DungeonQuest combat example from the module docs,
+    // value 1/12.
+    let m = vec![
+        vec![0.0, 2.0, -1.0],
+        vec![-1.0, 0.0, 1.0],
+        vec![1.0, -1.0, 0.0],
+    ];
+    let payoffs = Array2::from_shape_fn((3, 3), |(i, j)| m[i][j]);
+    let mut s = Schema::from_matrix(m);
+    let soln = s.solve();
+
+    let mut optimal = OptimalStrategy::from_row(&soln, 42);
+    let mut opponent = AlwaysPick(0);
+    let (score, _) = run_match(&payoffs.view(), &mut optimal, &mut opponent, 50_000);
+    assert!(score / 50_000.0 > soln.value - 0.05);
+}
+
+#[test]
+fn test_run_tournament_score_table() {
+    let payoffs = Array2::from_shape_fn((2, 2), |(i, j)| {
+        [[1.0, -1.0], [-1.0, 1.0]][i][j]
+    });
+    let factories: Vec<StrategyFactory> = vec![
+        Box::new(|| Box::new(AlwaysPick(0)) as Box<dyn Strategy>),
+        Box::new(|| Box::new(AlwaysPick(1)) as Box<dyn Strategy>),
+    ];
+    let table = run_tournament(&factories, &payoffs.view(), 10, 3);
+    assert_eq!(table.dim(), (2, 2));
+    assert!(eqish(table[(0, 0)], 10.0));
+    assert!(eqish(table[(0, 1)], -10.0));
+    assert!(eqish(table[(1, 0)], -10.0));
+    assert!(eqish(table[(1, 1)], 10.0));
+}
+
+#[test]
+fn test_run_match_optimal_non_square() {
+    // A 2-row, 3-column matrix, so `left_strategy` (len 3)
+    // and `top_strategy` (len 2) differ in length; using the
+    // wrong one as `strat_a`'s (row-indexed) moves would panic
+    // on out-of-bounds row indices.
+    let m = vec![vec![2.0, 6.0, 6.0], vec![7.0, 12.0, 0.0]];
+    let payoffs = Array2::from_shape_fn((2, 3), |(i, j)| m[i][j]);
+    let mut s = Schema::from_matrix(m);
+    let soln = s.solve();
+    assert_eq!(soln.top_strategy.len(), 2);
+    assert_eq!(soln.left_strategy.len(), 3);
+
+    let mut row_player = OptimalStrategy::from_row(&soln, 7);
+    let mut col_player = OptimalStrategy::from_col(&soln, 8);
+    let (score_a, score_b) =
+        run_match(&payoffs.view(), &mut row_player, &mut col_player, 1_000);
+    assert!(eqish(score_a + score_b, 0.0));
+}
+
+#[test]
+fn test_solve_2x2_exact_matches_solve() {
+    // Matching pennies: no saddle point, value 0, each
+    // player mixes 50/50.
+    let m = vec![vec![1.0, -1.0], vec![-1.0, 1.0]];
+    let mut pivot_schema = Schema::from_matrix(m.clone());
+    let pivot_soln = pivot_schema.solve();
+    let exact_soln = Schema::from_matrix(m).solve_2x2_exact();
+
+    assert!(eqish(exact_soln.value, pivot_soln.value));
+    for (&e, &p) in exact_soln.left_strategy.iter().zip(pivot_soln.left_strategy.iter()) {
+        assert!(eqish(e, p));
+    }
+    for (&e, &p) in exact_soln.top_strategy.iter().zip(pivot_soln.top_strategy.iter()) {
+        assert!(eqish(e, p));
+    }
+    assert!(eqish(exact_soln.value, 0.0));
+    assert!(eqish(exact_soln.top_strategy[0], 0.5));
+    assert!(eqish(exact_soln.left_strategy[0], 0.5));
+}
+
+#[test]
+fn test_solve_2x2_exact_falls_back_to_saddle_point() {
+    let s = Schema::from_matrix(vec![vec![10.0, 3.0], vec![4.0, 1.0]]);
+    let soln = s.solve_2x2_exact();
+    assert!(eqish(soln.value, 3.0));
+    assert!(eqish(soln.left_strategy[0], 0.0));
+    assert!(eqish(soln.left_strategy[1], 1.0));
+    assert!(eqish(soln.top_strategy[0], 1.0));
+    assert!(eqish(soln.top_strategy[1], 0.0));
+}